@@ -1,11 +1,14 @@
 // warn on lints, that are included in `rust-lang/rust`s bootstrap
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
-use std::env;
-use std::path::PathBuf;
-use std::process::{self, Command};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::{env, fs};
 
-use anstream::println;
+use anstream::{eprintln, print, println};
+use serde::{Deserialize, Serialize};
 
 #[allow(clippy::ignored_unit_patterns)]
 fn show_help() {
@@ -33,6 +36,9 @@ pub fn main() {
     if let Some(pos) = env::args().position(|a| a == "--explain") {
         if let Some(mut lint) = env::args().nth(pos + 1) {
             lint.make_ascii_lowercase();
+            if let Err(code) = explain_lint(&lint) {
+                process::exit(code);
+            }
         } else {
             show_help();
         }
@@ -44,10 +50,97 @@ pub fn main() {
     }
 }
 
+/// Prints the documentation for `lint_name`, delegating to `rpl-driver` so the
+/// explanation always matches the lint table the driver was built with.
+///
+/// The driver is invoked with `--rpl-explain=<lint>` and is expected to print
+/// the rendered doc text (already formatted for `color_print`/`anstream`) to
+/// stdout and exit successfully, or print an "unknown lint" message to stderr
+/// and exit non-zero.
+fn explain_lint(lint_name: &str) -> Result<(), i32> {
+    let lint_name = strip_rpl_prefix(lint_name);
+
+    run_explain_command(Command::new(RplCmd::path()).arg(format!("--rpl-explain={lint_name}")))
+}
+
+/// Strips the `rpl::` prefix a lint name may have been given on the command
+/// line (`cargo rpl --explain rpl::some_lint`), since `rpl-driver` is invoked
+/// with the bare lint name.
+fn strip_rpl_prefix(lint_name: &str) -> &str {
+    lint_name.strip_prefix("rpl::").unwrap_or(lint_name)
+}
+
+/// Runs `cmd`, printing its stdout on success and its stderr (propagating its
+/// exit code) on failure. Split out from [`explain_lint`] so tests can run an
+/// arbitrary command instead of the real `rpl-driver` binary.
+fn run_explain_command(cmd: &mut Command) -> Result<(), i32> {
+    let output = cmd.output().expect("could not run rpl-driver");
+
+    if output.status.success() {
+        println!("{}", String::from_utf8_lossy(&output.stdout));
+        Ok(())
+    } else {
+        eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+        Err(output.status.code().unwrap_or(-1))
+    }
+}
+
+/// Encodes `rpl_args` into the value passed to the driver via the `RPL_ARGS`
+/// env var. Each argument is written as a length-prefixed record, `len:arg:`
+/// (`len` is the argument's byte length; the separator is not a NUL byte
+/// because an env var value cannot contain one), so the round trip is lossless no
+/// matter what the argument contains: paths, lint names, regexes, newlines,
+/// unicode, or even an empty string. `rpl-driver`'s decoder must mirror
+/// `decode_rpl_args` below.
+fn encode_rpl_args(rpl_args: &[String]) -> String {
+    let mut encoded = String::new();
+    for arg in rpl_args {
+        encoded.push_str(&arg.len().to_string());
+        encoded.push(':');
+        encoded.push_str(arg);
+        encoded.push(':');
+    }
+    encoded
+}
+
+/// Reverses [`encode_rpl_args`]. Kept alongside it (and exercised by the same
+/// round-trip tests) so the wire format can't drift between the two ends.
+#[allow(dead_code)]
+fn decode_rpl_args(encoded: &str) -> Vec<String> {
+    let bytes = encoded.as_bytes();
+    let mut args = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let len_start = i;
+        while bytes[i] != b':' {
+            i += 1;
+        }
+        let len: usize = encoded[len_start..i]
+            .parse()
+            .expect("RPL_ARGS had a malformed length prefix");
+        i += 1;
+
+        let arg = &encoded[i..i + len];
+        args.push(arg.to_string());
+        i += len;
+
+        assert_eq!(bytes[i], b':', "RPL_ARGS record missing terminator");
+        i += 1;
+    }
+
+    args
+}
+
 struct RplCmd {
     cargo_subcommand: &'static str,
     args: Vec<String>,
     rpl_args: Vec<String>,
+    fix: bool,
+    broken_code: bool,
+    allow_no_vcs: bool,
+    allow_dirty: bool,
+    emit_findings: Option<PathBuf>,
 }
 
 impl RplCmd {
@@ -55,14 +148,36 @@ impl RplCmd {
     where
         I: Iterator<Item = String>,
     {
-        let mut cargo_subcommand = "check";
+        let cargo_subcommand = "check";
         let mut args = vec![];
         let mut rpl_args: Vec<String> = vec![];
+        let mut fix = false;
+        let mut broken_code = false;
+        let mut allow_no_vcs = false;
+        let mut allow_dirty = false;
+        let mut emit_findings = None;
 
         for arg in old_args.by_ref() {
+            if let Some(path) = arg.strip_prefix("--emit-findings=") {
+                emit_findings = Some(PathBuf::from(path));
+                continue;
+            }
+
             match arg.as_str() {
                 "--fix" => {
-                    cargo_subcommand = "fix";
+                    fix = true;
+                    continue;
+                },
+                "--broken-code" => {
+                    broken_code = true;
+                    continue;
+                },
+                "--allow-no-vcs" => {
+                    allow_no_vcs = true;
+                    continue;
+                },
+                "--allow-dirty" => {
+                    allow_dirty = true;
                     continue;
                 },
                 "--no-deps" => {
@@ -76,8 +191,9 @@ impl RplCmd {
             args.push(arg);
         }
 
+        rpl_args.append(&mut manifest_lint_args(&args));
         rpl_args.append(&mut (old_args.collect()));
-        if cargo_subcommand == "fix" && !rpl_args.iter().any(|arg| arg == "--no-deps") {
+        if fix && !rpl_args.iter().any(|arg| arg == "--no-deps") {
             rpl_args.push("--no-deps".into());
         }
 
@@ -85,6 +201,11 @@ impl RplCmd {
             cargo_subcommand,
             args,
             rpl_args,
+            fix,
+            broken_code,
+            allow_no_vcs,
+            allow_dirty,
+            emit_findings,
         }
     }
 
@@ -102,26 +223,190 @@ impl RplCmd {
 
     fn into_std_cmd(self) -> Command {
         let mut cmd = Command::new(env::var("CARGO").unwrap_or("cargo".into()));
-        let rpl_args: String = self
-            .rpl_args
-            .iter()
-            .fold(String::new(), |s, arg| s + arg + "__RPL_HACKERY__");
 
         cmd.env("RUSTC_WORKSPACE_WRAPPER", Self::path())
-            .env("RPL_ARGS", rpl_args)
+            .env("RPL_ARGS", encode_rpl_args(&self.rpl_args))
             .arg(self.cargo_subcommand)
             .args(&self.args);
 
+        if self.fix {
+            cmd.arg("--message-format=json");
+            if !self.args.iter().any(|arg| arg == "--all-targets") {
+                cmd.arg("--all-targets");
+            }
+        } else if self.emit_findings.is_some() {
+            cmd.arg("--message-format=json-diagnostic-rendered-ansi");
+        }
+
         cmd
     }
 }
 
+/// A single entry of a `[lints.rpl]` (or `[workspace.lints.rpl]`) table, e.g.
+/// `some_lint = "warn"` or `some_lint = { level = "deny", priority = 1 }`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LintSetting {
+    Level(String),
+    Detailed {
+        level: String,
+        #[serde(default)]
+        priority: i32,
+    },
+}
+
+impl LintSetting {
+    fn level(&self) -> &str {
+        match self {
+            Self::Level(level) | Self::Detailed { level, .. } => level,
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        match self {
+            Self::Level(_) => 0,
+            Self::Detailed { priority, .. } => *priority,
+        }
+    }
+}
+
+fn rpl_lints_table(manifest: &toml::Value) -> Option<HashMap<String, LintSetting>> {
+    manifest.get("lints")?.get("rpl")?.clone().try_into().ok()
+}
+
+fn workspace_rpl_lints_table(manifest: &toml::Value) -> Option<HashMap<String, LintSetting>> {
+    manifest.get("workspace")?.get("lints")?.get("rpl")?.clone().try_into().ok()
+}
+
+/// Finds the manifest path from either `--manifest-path <path>` or
+/// `--manifest-path=<path>`, both of which Cargo accepts.
+fn find_manifest_path(args: &[String]) -> Option<&str> {
+    if let Some(path) = args.iter().find_map(|a| a.strip_prefix("--manifest-path=")) {
+        return Some(path);
+    }
+
+    let pos = args.iter().position(|a| a == "--manifest-path")?;
+    args.get(pos + 1).map(String::as_str)
+}
+
+/// Picks out the `--frozen`/`--locked`/`--offline` flags so they can be
+/// forwarded to the internal `cargo_metadata` lookup, keeping it bound by the
+/// same constraints the user passed to `cargo rpl`.
+fn forwarded_cargo_options(args: &[String]) -> Vec<String> {
+    args.iter()
+        .filter(|arg| matches!(arg.as_str(), "--frozen" | "--locked" | "--offline"))
+        .cloned()
+        .collect()
+}
+
+/// Loads the package's (or, if it opts in with `lints.workspace = true`, the
+/// workspace's) `[lints.rpl]` table via `cargo_metadata` and translates it
+/// into `-A/-W/-D/-F rpl::<lint>` arguments, ordered so that higher-priority
+/// entries are appended last and win over lower-priority ones. Command-line
+/// flags are appended after these by the caller, so they win over the
+/// manifest regardless of priority.
+///
+/// Recognizes `--manifest-path <path>` and `--manifest-path=<path>` (Cargo
+/// accepts both), and forwards `--frozen`/`--locked`/`--offline` to the
+/// `cargo metadata` invocation so the lookup honors the same constraints the
+/// user passed to `cargo rpl`.
+fn manifest_lint_args(args: &[String]) -> Vec<String> {
+    let mut cmd = cargo_metadata::MetadataCommand::new();
+    cmd.no_deps();
+
+    if let Some(path) = find_manifest_path(args) {
+        cmd.manifest_path(path);
+    }
+
+    let other_options = forwarded_cargo_options(args);
+    if !other_options.is_empty() {
+        cmd.other_options(other_options);
+    }
+
+    let Ok(metadata) = cmd.exec() else {
+        return vec![];
+    };
+
+    let Some(package) = metadata.root_package() else {
+        return vec![];
+    };
+
+    let Ok(manifest) = fs::read_to_string(&package.manifest_path) else {
+        return vec![];
+    };
+    let Ok(manifest) = manifest.parse::<toml::Value>() else {
+        return vec![];
+    };
+
+    let uses_workspace_lints = manifest
+        .get("lints")
+        .and_then(|lints| lints.get("workspace"))
+        .and_then(toml::Value::as_bool)
+        .unwrap_or(false);
+
+    let lints = if uses_workspace_lints {
+        let Ok(workspace_manifest) = fs::read_to_string(metadata.workspace_root.join("Cargo.toml")) else {
+            return vec![];
+        };
+        let Ok(workspace_manifest) = workspace_manifest.parse::<toml::Value>() else {
+            return vec![];
+        };
+        workspace_rpl_lints_table(&workspace_manifest)
+    } else {
+        rpl_lints_table(&manifest)
+    };
+
+    let Some(lints) = lints else {
+        return vec![];
+    };
+
+    translate_lints_to_args(lints)
+}
+
+/// Translates a `[lints.rpl]` table into the `-A/-W/-D/-F` flags `rustc`
+/// expects, sorted so that higher-`priority` entries are appended last (and
+/// therefore win over lower-priority ones when rustc resolves duplicates).
+/// The level flags must be attached directly to the lint name with no `=`
+/// (`-Arpl::foo`, not `-A=rpl::foo`) — rustc treats the latter as a lint
+/// literally named `=rpl::foo` and rejects it as unknown.
+fn translate_lints_to_args(lints: HashMap<String, LintSetting>) -> Vec<String> {
+    let mut lints: Vec<(String, LintSetting)> = lints.into_iter().collect();
+    lints.sort_by_key(|(_, setting)| setting.priority());
+
+    lints
+        .into_iter()
+        .filter_map(|(name, setting)| {
+            let flag = match setting.level() {
+                "allow" => "-A",
+                "warn" => "-W",
+                "deny" => "-D",
+                "forbid" => "-F",
+                _ => return None,
+            };
+            Some(format!("{flag}rpl::{name}"))
+        })
+        .collect()
+}
+
 fn process<I>(old_args: I) -> Result<(), i32>
 where
     I: Iterator<Item = String>,
 {
     let cmd = RplCmd::new(old_args);
 
+    if cmd.fix && cmd.emit_findings.is_some() {
+        eprintln!("error: `--fix` and `--emit-findings` cannot be used together");
+        return Err(1);
+    }
+
+    if cmd.fix {
+        return run_fix(cmd);
+    }
+
+    if let Some(findings_path) = cmd.emit_findings.clone() {
+        return run_emit_findings(cmd, &findings_path);
+    }
+
     let mut cmd = cmd.into_std_cmd();
 
     let exit_status = cmd
@@ -137,6 +422,283 @@ where
     }
 }
 
+/// Mirrors `cargo fix`'s safety model: refuse to touch the working tree unless
+/// it's clean, or the user explicitly opted out of that check.
+fn check_vcs(allow_no_vcs: bool, allow_dirty: bool) -> Result<(), i32> {
+    let in_work_tree = Command::new("git")
+        .args(["rev-parse", "--is-inside-work-tree"])
+        .output()
+        .is_ok_and(|output| output.status.success());
+
+    if !in_work_tree {
+        if allow_no_vcs {
+            return Ok(());
+        }
+
+        eprintln!("error: no VCS found for this package and `--allow-no-vcs` was not specified");
+        return Err(1);
+    }
+
+    if allow_dirty {
+        return Ok(());
+    }
+
+    let status = Command::new("git")
+        .args(["status", "--porcelain"])
+        .output()
+        .expect("could not run git");
+
+    if !status.stdout.is_empty() {
+        eprintln!(
+            "error: the working directory of this package has uncommitted changes, and `--fix` can \
+             potentially perform destructive changes; if you'd like to suppress this error pass \
+             `--allow-dirty`"
+        );
+        return Err(1);
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason: String,
+    message: Option<CompilerDiagnostic>,
+}
+
+#[derive(Deserialize)]
+struct CompilerDiagnostic {
+    spans: Vec<DiagnosticSpan>,
+    #[serde(default)]
+    children: Vec<CompilerDiagnostic>,
+}
+
+#[derive(Deserialize)]
+struct DiagnosticSpan {
+    file_name: String,
+    byte_start: u32,
+    byte_end: u32,
+    suggested_replacement: Option<String>,
+    suggestion_applicability: Option<String>,
+}
+
+/// Parses `cargo check --message-format=json` output and collects every
+/// suggested replacement the compiler marked `MachineApplicable`, keyed by
+/// the file it applies to.
+fn collect_machine_applicable_suggestions(json_output: &str) -> HashMap<String, Vec<(u32, u32, String)>> {
+    let mut suggestions: HashMap<String, Vec<(u32, u32, String)>> = HashMap::new();
+
+    for line in json_output.lines() {
+        let Ok(msg) = serde_json::from_str::<CargoMessage>(line) else {
+            continue;
+        };
+
+        if msg.reason != "compiler-message" {
+            continue;
+        }
+
+        let Some(diagnostic) = msg.message else {
+            continue;
+        };
+
+        // Suggestions usually live on child diagnostics (`help: ...`), so walk them too.
+        let mut pending = vec![diagnostic];
+        while let Some(diagnostic) = pending.pop() {
+            pending.extend(diagnostic.children);
+
+            for span in diagnostic.spans {
+                if span.suggestion_applicability.as_deref() != Some("MachineApplicable") {
+                    continue;
+                }
+
+                let Some(replacement) = span.suggested_replacement else {
+                    continue;
+                };
+
+                suggestions
+                    .entry(span.file_name)
+                    .or_default()
+                    .push((span.byte_start, span.byte_end, replacement));
+            }
+        }
+    }
+
+    suggestions
+}
+
+/// Splices the collected replacements into each file, from the end of the
+/// file towards the start so earlier byte offsets stay valid, skipping any
+/// replacement that overlaps one already applied.
+fn apply_suggestions_to_files(suggestions: HashMap<String, Vec<(u32, u32, String)>>) {
+    for (file, mut replacements) in suggestions {
+        replacements.sort_by(|a, b| b.0.cmp(&a.0));
+
+        let Ok(mut contents) = fs::read_to_string(&file) else {
+            continue;
+        };
+
+        let mut applied_up_to = contents.len() as u32;
+        for (start, end, replacement) in replacements {
+            if end > applied_up_to {
+                continue;
+            }
+
+            contents.replace_range(start as usize..end as usize, &replacement);
+            applied_up_to = start;
+        }
+
+        let _ = fs::write(&file, contents);
+    }
+}
+
+/// Self-contained replacement for delegating `--fix` to `cargo fix`: runs the
+/// check with JSON diagnostics, applies every `MachineApplicable` suggestion
+/// RPL (or rustc) produced, and otherwise reports the check's exit status.
+fn run_fix(cmd: RplCmd) -> Result<(), i32> {
+    check_vcs(cmd.allow_no_vcs, cmd.allow_dirty)?;
+
+    let broken_code = cmd.broken_code;
+    let mut std_cmd = cmd.into_std_cmd();
+    std_cmd.stdout(Stdio::piped());
+
+    let output = std_cmd.output().expect("could not run cargo");
+
+    if !output.status.success() {
+        eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+
+        if !broken_code {
+            return Err(output.status.code().unwrap_or(-1));
+        }
+    }
+
+    let json_output = String::from_utf8_lossy(&output.stdout);
+    let suggestions = collect_machine_applicable_suggestions(&json_output);
+    apply_suggestions_to_files(suggestions);
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(output.status.code().unwrap_or(-1))
+    }
+}
+
+/// Schema version for the `--emit-findings` JSON export. Bump this whenever
+/// the shape of [`Finding`] changes so downstream tooling can detect it.
+const FINDINGS_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct FindingsReport {
+    schema_version: u32,
+    findings: Vec<Finding>,
+}
+
+#[derive(Serialize)]
+struct Finding {
+    lint: String,
+    level: String,
+    message: String,
+    file: String,
+    line: u32,
+    column: u32,
+}
+
+#[derive(Deserialize)]
+struct RenderedCargoMessage {
+    reason: String,
+    message: Option<RenderedDiagnostic>,
+}
+
+#[derive(Deserialize)]
+struct RenderedDiagnostic {
+    code: Option<DiagnosticCode>,
+    level: String,
+    message: String,
+    spans: Vec<FindingSpan>,
+    rendered: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct DiagnosticCode {
+    code: String,
+}
+
+#[derive(Deserialize)]
+struct FindingSpan {
+    file_name: String,
+    line_start: u32,
+    column_start: u32,
+    is_primary: bool,
+}
+
+/// Runs the check with `--message-format=json-diagnostic-rendered-ansi`,
+/// still prints each diagnostic's normal rendered form to the terminal, and
+/// additionally collects the ones whose lint code is RPL-originated into a
+/// versioned JSON report (a `schema_version` and a `findings` array) written
+/// to `findings_path` for CI consumption.
+fn run_emit_findings(cmd: RplCmd, findings_path: &Path) -> Result<(), i32> {
+    let mut std_cmd = cmd.into_std_cmd();
+    std_cmd.stdout(Stdio::piped());
+
+    let mut child = std_cmd.spawn().expect("could not run cargo");
+    let stdout = child.stdout.take().expect("cargo stdout was not piped");
+
+    let mut findings = vec![];
+    for line in BufReader::new(stdout).lines() {
+        let line = line.expect("could not read cargo output");
+
+        let Ok(msg) = serde_json::from_str::<RenderedCargoMessage>(&line) else {
+            continue;
+        };
+        if msg.reason != "compiler-message" {
+            continue;
+        }
+        let Some(diagnostic) = msg.message else {
+            continue;
+        };
+
+        if let Some(rendered) = &diagnostic.rendered {
+            print!("{rendered}");
+        }
+
+        let Some(code) = &diagnostic.code else {
+            continue;
+        };
+        if !code.code.starts_with("rpl::") {
+            continue;
+        }
+        let Some(span) = diagnostic.spans.iter().find(|span| span.is_primary) else {
+            continue;
+        };
+
+        findings.push(Finding {
+            lint: code.code.clone(),
+            level: diagnostic.level.clone(),
+            message: diagnostic.message.clone(),
+            file: span.file_name.clone(),
+            line: span.line_start,
+            column: span.column_start,
+        });
+    }
+
+    let exit_status = child.wait().expect("failed to wait for cargo?");
+
+    let report = FindingsReport {
+        schema_version: FINDINGS_SCHEMA_VERSION,
+        findings,
+    };
+    let report = serde_json::to_string_pretty(&report).expect("could not serialize findings report");
+    if let Err(err) = fs::write(findings_path, report) {
+        eprintln!("error: could not write findings to {}: {err}", findings_path.display());
+        return Err(1);
+    }
+
+    if exit_status.success() {
+        Ok(())
+    } else {
+        Err(exit_status.code().unwrap_or(-1))
+    }
+}
+
 #[must_use]
 pub fn help_message() -> &'static str {
     color_print::cstr!(
@@ -148,6 +710,11 @@ pub fn help_message() -> &'static str {
 <green,bold>Common options:</>
     <cyan,bold>--no-deps</>                Run RPL only on the given crate, without linting the dependencies
     <cyan,bold>--fix</>                    Automatically apply lint suggestions. This flag implies <cyan>--no-deps</> and <cyan>--all-targets</>
+    <cyan,bold>--broken-code</>            Apply fixes even if the check fails with errors
+    <cyan,bold>--allow-no-vcs</>           Allow <cyan>--fix</> to run even if the package isn't in a VCS
+    <cyan,bold>--allow-dirty</>            Allow <cyan>--fix</> to run even if the working directory has changes
+    <cyan,bold>--emit-findings</>=<cyan><<PATH>></>  Write RPL's findings as versioned JSON to <cyan><<PATH>></>
+                              (cannot be combined with <cyan,bold>--fix</>)
     <cyan,bold>-h</>, <cyan,bold>--help</>               Print this message
     <cyan,bold>-V</>, <cyan,bold>--version</>            Print version info and exit
     <cyan,bold>--explain [LINT]</>         Print the documentation for a given lint
@@ -168,20 +735,71 @@ To allow or deny a lint from the command line you can use <cyan,bold>cargo rpl -
     <cyan,bold>--frozen</>                Require Cargo.lock and cache are up to date
     <cyan,bold>--locked</>                Require Cargo.lock is up to date
     <cyan,bold>--offline</>               Run without accessing the network
+
+A <cyan,bold>[lints.rpl]</> table in <cyan,bold>Cargo.toml</> can also set lint levels, the same way Cargo's own
+<cyan,bold>[lints]</> table does. Command-line <cyan,bold>-W</>/<cyan,bold>-A</>/<cyan,bold>-D</>/<cyan,bold>-F</> flags always win over the manifest.
 ")
 }
 #[cfg(test)]
 mod tests {
-    use super::RplCmd;
+    use std::collections::HashMap;
+
+    use super::{
+        collect_machine_applicable_suggestions, decode_rpl_args, encode_rpl_args, find_manifest_path,
+        forwarded_cargo_options, run_explain_command, strip_rpl_prefix, translate_lints_to_args, Command, Finding,
+        FindingsReport, LintSetting, RplCmd, FINDINGS_SCHEMA_VERSION,
+    };
+
+    #[test]
+    fn strip_rpl_prefix_removes_the_rpl_prefix() {
+        assert_eq!(strip_rpl_prefix("rpl::some_lint"), "some_lint");
+    }
+
+    #[test]
+    fn strip_rpl_prefix_leaves_bare_names_unchanged() {
+        assert_eq!(strip_rpl_prefix("some_lint"), "some_lint");
+    }
+
+    #[test]
+    fn run_explain_command_propagates_a_failing_exit_code() {
+        let result = run_explain_command(Command::new("sh").args(["-c", "exit 7"]));
+        assert_eq!(result, Err(7));
+    }
+
+    #[test]
+    fn run_explain_command_succeeds_and_prints_stdout_on_success() {
+        let result = run_explain_command(Command::new("sh").args(["-c", "echo docs"]));
+        assert_eq!(result, Ok(()));
+    }
 
     #[test]
     fn fix() {
         let args = "cargo rpl --fix".split_whitespace().map(ToString::to_string);
         let cmd = RplCmd::new(args);
-        assert_eq!("fix", cmd.cargo_subcommand);
+        assert!(cmd.fix);
         assert!(!cmd.args.iter().any(|arg| arg.ends_with("unstable-options")));
     }
 
+    #[test]
+    fn fix_safety_flags_default_to_false() {
+        let args = "cargo rpl --fix".split_whitespace().map(ToString::to_string);
+        let cmd = RplCmd::new(args);
+        assert!(!cmd.broken_code);
+        assert!(!cmd.allow_no_vcs);
+        assert!(!cmd.allow_dirty);
+    }
+
+    #[test]
+    fn fix_safety_flags_are_parsed() {
+        let args = "cargo rpl --fix --broken-code --allow-no-vcs --allow-dirty"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = RplCmd::new(args);
+        assert!(cmd.broken_code);
+        assert!(cmd.allow_no_vcs);
+        assert!(cmd.allow_dirty);
+    }
+
     #[test]
     fn fix_implies_no_deps() {
         let args = "cargo rpl --fix".split_whitespace().map(ToString::to_string);
@@ -203,5 +821,150 @@ mod tests {
         let args = "cargo rpl".split_whitespace().map(ToString::to_string);
         let cmd = RplCmd::new(args);
         assert_eq!("check", cmd.cargo_subcommand);
+        assert!(!cmd.fix);
+    }
+
+    #[test]
+    fn collects_only_machine_applicable_suggestions() {
+        let json_output = r#"
+            {"reason":"compiler-message","message":{"spans":[{"file_name":"src/lib.rs","byte_start":10,"byte_end":14,"suggested_replacement":"None","suggestion_applicability":"MachineApplicable"}]}}
+            {"reason":"compiler-message","message":{"spans":[{"file_name":"src/lib.rs","byte_start":20,"byte_end":24,"suggested_replacement":"maybe","suggestion_applicability":"MaybeIncorrect"}]}}
+            {"reason":"build-finished","message":null}
+        "#;
+        let suggestions = collect_machine_applicable_suggestions(json_output);
+        let replacements = &suggestions["src/lib.rs"];
+        assert_eq!(replacements.len(), 1);
+        assert_eq!(replacements[0], (10, 14, "None".to_string()));
+    }
+
+    #[test]
+    fn lint_setting_translates_to_attached_rustc_flags() {
+        let mut lints = HashMap::new();
+        lints.insert("foo".to_string(), LintSetting::Level("allow".to_string()));
+        lints.insert("bar".to_string(), LintSetting::Detailed {
+            level: "deny".to_string(),
+            priority: 0,
+        });
+
+        let args = translate_lints_to_args(lints);
+        assert!(args.contains(&"-Arpl::foo".to_string()));
+        assert!(args.contains(&"-Drpl::bar".to_string()));
+    }
+
+    #[test]
+    fn find_manifest_path_recognizes_the_two_token_form() {
+        let args = ["--manifest-path".to_string(), "foo/Cargo.toml".to_string()];
+        assert_eq!(find_manifest_path(&args), Some("foo/Cargo.toml"));
+    }
+
+    #[test]
+    fn find_manifest_path_recognizes_the_equals_form() {
+        let args = ["--manifest-path=foo/Cargo.toml".to_string()];
+        assert_eq!(find_manifest_path(&args), Some("foo/Cargo.toml"));
+    }
+
+    #[test]
+    fn find_manifest_path_is_none_when_absent() {
+        let args = ["--frozen".to_string()];
+        assert_eq!(find_manifest_path(&args), None);
+    }
+
+    #[test]
+    fn forwarded_cargo_options_picks_out_frozen_locked_offline() {
+        let args = [
+            "--frozen".to_string(),
+            "--manifest-path=foo/Cargo.toml".to_string(),
+            "--locked".to_string(),
+            "--offline".to_string(),
+        ];
+        assert_eq!(
+            forwarded_cargo_options(&args),
+            vec!["--frozen".to_string(), "--locked".to_string(), "--offline".to_string()]
+        );
+    }
+
+    #[test]
+    fn lint_setting_parses_short_and_detailed_form() {
+        let short: LintSetting = toml::Value::String("warn".into()).try_into().unwrap();
+        assert_eq!(short.level(), "warn");
+        assert_eq!(short.priority(), 0);
+
+        let detailed: LintSetting = toml::toml! { level = "deny", priority = 2 }.try_into().unwrap();
+        assert_eq!(detailed.level(), "deny");
+        assert_eq!(detailed.priority(), 2);
+    }
+
+    #[test]
+    fn rpl_args_round_trip_empty() {
+        let args: Vec<String> = vec![];
+        assert_eq!(decode_rpl_args(&encode_rpl_args(&args)), args);
+    }
+
+    #[test]
+    fn rpl_args_round_trip_empty_string_arg() {
+        let args = vec![String::new()];
+        assert_eq!(decode_rpl_args(&encode_rpl_args(&args)), args);
+    }
+
+    #[test]
+    fn rpl_args_round_trip_old_delimiter() {
+        let args = vec!["rpl::foo".to_string(), "__RPL_HACKERY__".to_string(), "bar".to_string()];
+        assert_eq!(decode_rpl_args(&encode_rpl_args(&args)), args);
+    }
+
+    #[test]
+    fn rpl_args_round_trip_newlines_and_unicode() {
+        let args = vec!["line one\nline two".to_string(), "花火".to_string(), "🦀".to_string()];
+        assert_eq!(decode_rpl_args(&encode_rpl_args(&args)), args);
+    }
+
+    #[test]
+    fn rpl_args_round_trip_mixed_with_empty_args() {
+        let args = vec![String::new(), "rpl::some_lint".to_string(), String::new()];
+        assert_eq!(decode_rpl_args(&encode_rpl_args(&args)), args);
+    }
+
+    #[test]
+    fn emit_findings_path_is_parsed() {
+        let args = "cargo rpl --emit-findings=findings.json"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = RplCmd::new(args);
+        assert_eq!(cmd.emit_findings, Some("findings.json".into()));
+    }
+
+    #[test]
+    fn emit_findings_defaults_to_none() {
+        let args = "cargo rpl".split_whitespace().map(ToString::to_string);
+        let cmd = RplCmd::new(args);
+        assert_eq!(cmd.emit_findings, None);
+    }
+
+    #[test]
+    fn fix_and_emit_findings_can_both_be_parsed_so_process_can_reject_the_combination() {
+        let args = "cargo rpl --fix --emit-findings=findings.json"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = RplCmd::new(args);
+        assert!(cmd.fix);
+        assert_eq!(cmd.emit_findings, Some("findings.json".into()));
+    }
+
+    #[test]
+    fn findings_report_serializes_with_schema_version() {
+        let report = FindingsReport {
+            schema_version: FINDINGS_SCHEMA_VERSION,
+            findings: vec![Finding {
+                lint: "rpl::some_lint".to_string(),
+                level: "warning".to_string(),
+                message: "did a questionable thing".to_string(),
+                file: "src/lib.rs".to_string(),
+                line: 12,
+                column: 5,
+            }],
+        };
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains(r#""schema_version":1"#));
+        assert!(json.contains(r#""lint":"rpl::some_lint""#));
     }
 }